@@ -0,0 +1,184 @@
+use blstrs::Scalar;
+use ff::{Field, PrimeField};
+use std::sync::Mutex;
+
+/*
+    `get_coeff_from_roots` recurses into a tree of `fft_mul` calls, and each one used to recompute
+    `Scalar::ROOT_OF_UNITY.pow_vartime(...)`, `omega.invert()`, `Scalar::from(n).invert()`, and a
+    full twiddle-factor table, from scratch, on top of allocating fresh buffers. `EvaluationDomain`
+    precomputes all of that once, for every power-of-two size up to a chosen maximum, so the
+    divide-and-conquer tree only ever looks values up and recycles buffers instead of reallocating
+    and recomputing them on every node.
+
+    Coset-FFT evaluation (shifting the domain by a fixed offset before transforming) is deliberately
+    not provided here: nothing in this crate currently evaluates a polynomial over an extended or
+    shifted domain (the opening/quotient path works entirely through `kate_division`), so there is
+    no caller to exercise it. Add it alongside `fft_in_place` below if and when one shows up.
+*/
+pub struct EvaluationDomain {
+    omegas: Vec<Scalar>,
+    omega_invs: Vec<Scalar>,
+    n_invs: Vec<Scalar>,
+    // twiddles[s][i] = omega(s)^i for i in 0..n/2, n = 2^s; inv_twiddles is the same table built
+    // from omega_inv(s). Precomputed once per size here instead of being recomputed on every
+    // `fft_in_place`/`ifft_in_place` call the way a generic FFT routine normally would.
+    twiddles: Vec<Vec<Scalar>>,
+    inv_twiddles: Vec<Vec<Scalar>>,
+    // A pool of buffers handed out by `take_scratch` and returned by `recycle_scratch`, so the
+    // many same-sized FFTs `fft_mul` performs across `get_coeff_from_roots`'s recursion reuse
+    // allocations instead of paying for a fresh `Vec` at every node. A `Mutex` (rather than a
+    // `RefCell`) because `get_coeff_from_roots` shares `&EvaluationDomain` across the worker
+    // threads `rayon::join` may run its recursion on.
+    scratch: Mutex<Vec<Vec<Scalar>>>,
+}
+
+impl EvaluationDomain {
+    /*
+        Builds a domain able to serve FFTs of any power-of-two size up to the smallest `2^s >=
+        max_size`.
+    */
+    pub fn new(max_size: usize) -> Self {
+        // This is the 2^32th root of unity
+        const ROOT_OF_UNITY: Scalar = Scalar::ROOT_OF_UNITY;
+
+        let max_log_size = max_size.max(1).next_power_of_two().trailing_zeros();
+
+        let omegas: Vec<Scalar> = (0..=max_log_size)
+            .map(|s| ROOT_OF_UNITY.pow_vartime([1u64 << (32 - s)]))
+            .collect();
+        let omega_invs: Vec<Scalar> = omegas.iter().map(|omega| omega.invert().unwrap()).collect();
+        let n_invs = (0..=max_log_size)
+            .map(|s| Scalar::from(1u64 << s).invert().unwrap())
+            .collect();
+
+        let twiddles = omegas
+            .iter()
+            .enumerate()
+            .map(|(s, &omega)| twiddle_table(omega, s as u32))
+            .collect();
+        let inv_twiddles = omega_invs
+            .iter()
+            .enumerate()
+            .map(|(s, &omega_inv)| twiddle_table(omega_inv, s as u32))
+            .collect();
+
+        Self {
+            omegas,
+            omega_invs,
+            n_invs,
+            twiddles,
+            inv_twiddles,
+            scratch: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The n-th root of unity for the domain of size `n = 2^s`.
+    pub fn omega(&self, s: u32) -> Scalar {
+        self.omegas[s as usize]
+    }
+
+    /// The inverse of `omega(s)`, used by the inverse FFT.
+    pub fn omega_inv(&self, s: u32) -> Scalar {
+        self.omega_invs[s as usize]
+    }
+
+    /// The inverse of `n = 2^s` as a field element, used to normalize an inverse FFT.
+    pub fn n_inv(&self, s: u32) -> Scalar {
+        self.n_invs[s as usize]
+    }
+
+    /// Transforms `values` in place via a radix-2 FFT over the domain of size `n = 2^s =
+    /// values.len()`, using the twiddle factors cached for that size.
+    pub fn fft_in_place(&self, values: &mut [Scalar], s: u32) {
+        butterfly(values, s, &self.twiddles[s as usize]);
+    }
+
+    /// Transforms `values` in place via the inverse of `fft_in_place`, including the `1/n`
+    /// normalization.
+    pub fn ifft_in_place(&self, values: &mut [Scalar], s: u32) {
+        butterfly(values, s, &self.inv_twiddles[s as usize]);
+        let n_inv = self.n_invs[s as usize];
+        values.iter_mut().for_each(|x| *x *= n_inv);
+    }
+
+    /// Borrows a zero-filled scratch buffer of length `n` from the domain's pool, allocating one
+    /// only if the pool is empty. Pair with `recycle_scratch` once done with the buffer so later
+    /// calls reuse the allocation instead of paying for a fresh one.
+    pub(crate) fn take_scratch(&self, n: usize) -> Vec<Scalar> {
+        let mut buf = self.scratch.lock().unwrap().pop().unwrap_or_default();
+        buf.clear();
+        buf.resize(n, Scalar::ZERO);
+        buf
+    }
+
+    /// Returns a buffer obtained from `take_scratch` to the domain's pool for reuse.
+    pub(crate) fn recycle_scratch(&self, buf: Vec<Scalar>) {
+        self.scratch.lock().unwrap().push(buf);
+    }
+}
+
+/// The flat twiddle-factor table `[omega^0, omega^1, ..., omega^(n/2 - 1)]` for the domain of size
+/// `n = 2^s` whose `n`-th root of unity is `omega`.
+fn twiddle_table(omega: Scalar, s: u32) -> Vec<Scalar> {
+    let half = if s == 0 { 0 } else { 1usize << (s - 1) };
+
+    (0..half)
+        .scan(Scalar::ONE, |w, _| {
+            let t = *w;
+            *w *= omega;
+            Some(t)
+        })
+        .collect()
+}
+
+/// In-place radix-2 decimation-in-time FFT/IFFT core shared by `fft_in_place` and
+/// `ifft_in_place`: bit-reverses `values`, then runs `s` butterfly stages indexing into the
+/// caller-supplied, precomputed `twiddles` table.
+fn butterfly(values: &mut [Scalar], s: u32, twiddles: &[Scalar]) {
+    let n = values.len();
+    debug_assert_eq!(n, 1usize << s);
+
+    for k in 0..n {
+        let rk = bitreverse(k, s as usize);
+        if k < rk {
+            values.swap(rk, k);
+        }
+    }
+
+    let mut chunk = 2usize;
+    let mut twiddle_chunk = n / 2;
+    for _ in 0..s {
+        values.chunks_mut(chunk).for_each(|group| {
+            let (left, right) = group.split_at_mut(chunk / 2);
+            let (a0, left) = left.split_at_mut(1);
+            let (b0, right) = right.split_at_mut(1);
+
+            let t = b0[0];
+            b0[0] = a0[0];
+            a0[0] += t;
+            b0[0] -= t;
+
+            left.iter_mut()
+                .zip(right.iter_mut())
+                .enumerate()
+                .for_each(|(i, (a, b))| {
+                    let mut t = *b;
+                    t *= twiddles[(i + 1) * twiddle_chunk];
+                    *b = *a;
+                    *a += t;
+                    *b -= t;
+                });
+        });
+        chunk *= 2;
+        twiddle_chunk /= 2;
+    }
+}
+
+fn bitreverse(mut n: usize, l: usize) -> usize {
+    let mut r = 0;
+    for _ in 0..l {
+        r = (r << 1) | (n & 1);
+        n >>= 1;
+    }
+    r
+}
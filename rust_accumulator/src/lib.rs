@@ -1,8 +1,12 @@
 use blstrs::{pairing, G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
-use ff::{Field, PrimeField};
+use ff::Field;
 use group::prime::PrimeCurveAffine;
 use group::Group;
-use halo2_proofs::arithmetic::best_fft;
+
+mod domain;
+mod transcript;
+pub use domain::EvaluationDomain;
+pub use transcript::Transcript;
 
 /*
     This module contains the functions that will be exported to C.
@@ -16,57 +20,52 @@ use halo2_proofs::arithmetic::best_fft;
 */
 
 /*
-    Performs polynomial multiplication using the Fast Fourier Transform (FFT) algorithm.
+    Performs polynomial multiplication using the Fast Fourier Transform (FFT) algorithm. `domain`
+    supplies, for every size this call (and its recursive siblings in `get_coeff_from_roots`) might
+    need, the roots of unity, their inverses, and the twiddle factors derived from them, plus a
+    pool of reusable buffers, so none of that is recomputed or reallocated on every node of the
+    divide-and-conquer tree.
 */
-pub fn fft_mul(left: &[Scalar], right: &[Scalar]) -> Vec<Scalar> {
+pub fn fft_mul(left: &[Scalar], right: &[Scalar], domain: &EvaluationDomain) -> Vec<Scalar> {
     let degree_image = left.len() + right.len() - 1;
 
-    // This is the 2^32th root of unity
-    const ROOT_OF_UNITY: Scalar = Scalar::ROOT_OF_UNITY;
-
     // Calculate the smallest n = 2^s such that 2^s >= degree_image
     let s: u32 = degree_image.next_power_of_two().trailing_zeros();
     let n: usize = 1 << s;
 
-    // Calculate the n-th root of unity and its inverse
-    let omega = ROOT_OF_UNITY.pow_vartime(&[(1u64 << (32 - s)) as u64]);
-
-    // Clone and resize the vectors
-    let mut left = left.to_vec();
-    let mut right = right.to_vec();
-    left.resize(n, Scalar::ZERO);
-    right.resize(n, Scalar::ZERO);
+    // Borrow scratch buffers from the domain's pool and fill them with the operands
+    let mut left_buf = domain.take_scratch(n);
+    let mut right_buf = domain.take_scratch(n);
+    left_buf[..left.len()].copy_from_slice(left);
+    right_buf[..right.len()].copy_from_slice(right);
 
-    // Perform FFT on the left and right vectors
-    best_fft(&mut left, omega, s);
-    best_fft(&mut right, omega, s);
-
-    // Perform point-wise multiplication of the transformed vectors
-    let mut result: Vec<Scalar> = left
-        .iter()
-        .zip(right.iter())
-        .map(|(a, b)| *a * *b)
-        .collect();
+    // Perform FFT on the left and right buffers, using the domain's cached twiddle factors
+    domain.fft_in_place(&mut left_buf, s);
+    domain.fft_in_place(&mut right_buf, s);
 
-    // Perform inverse FFT
-    best_fft(&mut result, omega.invert().unwrap(), s);
+    // Perform point-wise multiplication in place, reusing `left_buf` as the result buffer
+    left_buf
+        .iter_mut()
+        .zip(right_buf.iter())
+        .for_each(|(a, b)| *a *= *b);
+    domain.recycle_scratch(right_buf);
 
-    // Normalize the result by dividing by n
-    let n_inv = Scalar::from(n as u64).invert().unwrap();
-    result.iter_mut().for_each(|x| *x *= n_inv);
+    // Perform inverse FFT, including the 1/n normalization
+    domain.ifft_in_place(&mut left_buf, s);
 
     // Remove trailing zeros
-    result.truncate(degree_image);
+    left_buf.truncate(degree_image);
 
-    result
+    left_buf
 }
 
 /*
     This function calculates the coefficients of the polynomial with roots given by the input `roots`.
     The polynomial is of the form `f(x) = (x - roots[0]) * (x - roots[1]) * ... * (x - roots[n-1])`.
-    The function returns the coefficients of the polynomial in the form of a vector.
+    The function returns the coefficients of the polynomial in the form of a vector. `domain` must be
+    large enough to cover the biggest `fft_mul` this call tree will perform (see `EvaluationDomain`).
 */
-pub fn get_coeff_from_roots(roots: &[Scalar]) -> Vec<Scalar> {
+pub fn get_coeff_from_roots(roots: &[Scalar], domain: &EvaluationDomain) -> Vec<Scalar> {
     let n = roots.len();
 
     if n == 0 {
@@ -81,62 +80,756 @@ pub fn get_coeff_from_roots(roots: &[Scalar]) -> Vec<Scalar> {
 
     // Spawn parallel tasks for left and right halves (divide and conquer)
     let (left, right) = rayon::join(
-        || get_coeff_from_roots(&roots[..m]),
-        || get_coeff_from_roots(&roots[m..]),
+        || get_coeff_from_roots(&roots[..m], domain),
+        || get_coeff_from_roots(&roots[m..], domain),
     );
 
     // Multiply the coefficients of the left and right halves
-    fft_mul(&left, &right)
+    fft_mul(&left, &right, domain)
+}
+
+/*
+    Builds an `EvaluationDomain` sized for computing `get_coeff_from_roots` over `element_count`
+    elements, i.e. large enough for every `fft_mul` the divide-and-conquer recursion can produce.
+*/
+fn domain_for(element_count: usize) -> EvaluationDomain {
+    EvaluationDomain::new(element_count + 1)
 }
 
+/*
+    Divides the polynomial `poly` (coefficients low-degree first) by the linear factor `(x - point)`
+    using Kate/synthetic division: `poly(x) = quotient(x) * (x - point) + remainder`. The recurrence
+    runs from the leading coefficient down, `q_k = f_{k+1} + point * q_{k+1}`, which costs a single
+    pass over the coefficients instead of a general polynomial long division. When `point` is an
+    actual root of `poly` the remainder is zero, which is the case used to build membership witnesses.
+*/
+pub fn kate_division(poly: &[Scalar], point: Scalar) -> (Vec<Scalar>, Scalar) {
+    let n = poly.len();
+    assert!(n >= 1, "cannot divide an empty polynomial");
+
+    if n == 1 {
+        return (vec![], poly[0]);
+    }
+
+    let mut quotient = vec![Scalar::ZERO; n - 1];
+    quotient[n - 2] = poly[n - 1];
+    for k in (0..n - 2).rev() {
+        quotient[k] = poly[k + 1] + point * quotient[k + 1];
+    }
+    let remainder = poly[0] + point * quotient[0];
+
+    (quotient, remainder)
+}
+
+/*
+    Evaluates the polynomial `poly` (coefficients low-degree first) at `point` via Horner's method.
+*/
+pub fn eval_polynomial(poly: &[Scalar], point: Scalar) -> Scalar {
+    poly.iter()
+        .rev()
+        .fold(Scalar::ZERO, |acc, &coeff| acc * point + coeff)
+}
+
+/*
+    Computes the coefficients of the unique polynomial `r` of degree `< claims.len()` with
+    `r(z_i) = y_i` for every `(z_i, y_i)` in `claims`. Builds the full vanishing polynomial
+    `Z(x) = prod (x - z_i)` (via `get_coeff_from_roots` on the negated points, since that function
+    computes `prod (x + root)`), then for each claim divides `Z` by `(x - z_i)` to get the Lagrange
+    basis numerator `prod_{m != i} (x - z_m)` and scales it by `y_i / (that numerator evaluated at
+    z_i)` before accumulating it into the result.
+*/
+pub fn lagrange_interpolate(claims: &[(Scalar, Scalar)]) -> Vec<Scalar> {
+    let k = claims.len();
+    if k == 0 {
+        return vec![];
+    }
+
+    let negated_points: Vec<Scalar> = claims.iter().map(|(z, _)| -z).collect();
+    let domain = domain_for(negated_points.len());
+    let vanishing = get_coeff_from_roots(&negated_points, &domain);
+
+    let mut result = vec![Scalar::ZERO; k];
+    for &(z_i, y_i) in claims {
+        let (basis, _remainder) = kate_division(&vanishing, z_i);
+        let scale = y_i * eval_polynomial(&basis, z_i).invert().unwrap();
+        for (coeff, basis_coeff) in result.iter_mut().zip(basis.iter()) {
+            *coeff += scale * basis_coeff;
+        }
+    }
+
+    result
+}
+
+/// # Safety
+///
+/// `scalars_ptr`/`points_ptr` must point to at least `scalars_len`/`points_len` valid,
+/// readable elements, and `return_point` must point to valid, writable memory.
 #[no_mangle]
-pub extern "C" fn get_poly_commitment_g1(
+pub unsafe extern "C" fn get_poly_commitment_g1(
     return_point: *mut G1Projective,
     scalars_ptr: *const Scalar,
     scalars_len: usize,
     points_ptr: *const G1Projective,
     points_len: usize,
 ) {
-    // Safety block to handle raw pointers
-    unsafe {
-        // Create slices from the raw pointers
-        let scalars: &[Scalar] = std::slice::from_raw_parts(scalars_ptr, scalars_len);
-        let points: &[G1Projective] = std::slice::from_raw_parts(points_ptr, points_len);
+    // Create slices from the raw pointers
+    let scalars: &[Scalar] = std::slice::from_raw_parts(scalars_ptr, scalars_len);
+    let points: &[G1Projective] = std::slice::from_raw_parts(points_ptr, points_len);
 
-        // Get the roots polynomial coefficients using the provided scalars
-        let roots_poly = get_coeff_from_roots(scalars);
+    // Get the roots polynomial coefficients using the provided scalars
+    let domain = domain_for(scalars.len());
+    let roots_poly = get_coeff_from_roots(scalars, &domain);
 
-        // Perform MSM (Multi-Scalar Multiplication) with the polynomial coefficients and points
-        let commitment = G1Projective::multi_exp(points, &roots_poly);
+    // Perform MSM (Multi-Scalar Multiplication) with the polynomial coefficients and points
+    let commitment = G1Projective::multi_exp(points, &roots_poly);
 
-        // Store the result in the return_point
-        *return_point = commitment;
-    }
+    // Store the result in the return_point
+    *return_point = commitment;
 }
 
+/// # Safety
+///
+/// `scalars_ptr`/`points_ptr` must point to at least `scalars_len`/`points_len` valid,
+/// readable elements, and `return_point` must point to valid, writable memory.
 #[no_mangle]
-pub extern "C" fn get_poly_commitment_g2(
+pub unsafe extern "C" fn get_poly_commitment_g2(
     return_point: *mut G2Projective,
     scalars_ptr: *const Scalar,
     scalars_len: usize,
     points_ptr: *const G2Projective,
     points_len: usize,
 ) {
-    // Safety block to handle raw pointers
-    unsafe {
-        // Create slices from the raw pointers
-        let scalars: &[Scalar] = std::slice::from_raw_parts(scalars_ptr, scalars_len);
-        let points: &[G2Projective] = std::slice::from_raw_parts(points_ptr, points_len);
+    // Create slices from the raw pointers
+    let scalars: &[Scalar] = std::slice::from_raw_parts(scalars_ptr, scalars_len);
+    let points: &[G2Projective] = std::slice::from_raw_parts(points_ptr, points_len);
+
+    // Get the roots polynomial coefficients using the provided scalars
+    let domain = domain_for(scalars.len());
+    let roots_poly = get_coeff_from_roots(scalars, &domain);
+
+    // Perform MSM (Multi-Scalar Multiplication) with the polynomial coefficients and points
+    let commitment = G2Projective::multi_exp(points, &roots_poly);
+
+    // Store the result in the return_point
+    *return_point = commitment;
+}
+
+/*
+    Produces a KZG-style membership witness `W = g1^{q(tau)}` for a single accumulated element `a`.
+    `f` is the polynomial built by `get_coeff_from_roots` from `scalars`, i.e. `f(x) = prod (x +
+    scalars[i])`, so the member `a` is a root of `f` at `x = -a` and `q(x) = f(x) / (x - (-a))` is
+    exact. `kate_division` does the division; its (zero) remainder is dropped.
+*/
+/// # Safety
+///
+/// `scalars_ptr`/`points_ptr` must point to at least `scalars_len`/`points_len` valid,
+/// readable elements, and `return_point` must point to valid, writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn get_membership_witness_g1(
+    return_point: *mut G1Projective,
+    scalars_ptr: *const Scalar,
+    scalars_len: usize,
+    element: Scalar,
+    points_ptr: *const G1Projective,
+    points_len: usize,
+) {
+    // Create slices from the raw pointers
+    let scalars: &[Scalar] = std::slice::from_raw_parts(scalars_ptr, scalars_len);
+    let points: &[G1Projective] = std::slice::from_raw_parts(points_ptr, points_len);
+
+    // Get the coefficients of f(x) = prod (x + scalars[i])
+    let domain = domain_for(scalars.len());
+    let roots_poly = get_coeff_from_roots(scalars, &domain);
+
+    // f's root for `element` sits at x = -element, so divide out (x - (-element))
+    let (quotient_poly, _remainder) = kate_division(&roots_poly, -element);
+
+    // Commit to q(x) via MSM against the matching prefix of the G1 setup
+    let witness = G1Projective::multi_exp(&points[..quotient_poly.len()], &quotient_poly);
+
+    // Store the result in the return_point
+    *return_point = witness;
+}
+
+/*
+    Verifies a membership witness via the pairing check `e(A, g2) == e(W, [tau]_2 + element*[1]_2)`.
+    This holds because `f(tau) = q(tau) * (tau - (-element))`, i.e. `f(tau) = q(tau) * (tau +
+    element)`, whenever `element` is a genuine member. `g2_tau` is `g2^tau` from the trusted setup,
+    i.e. the second element of the G2 powers-of-tau.
+*/
+/// # Safety
+///
+/// `commitment`, `witness`, and `g2_tau` must each point to a valid, readable value.
+#[no_mangle]
+pub unsafe extern "C" fn verify_membership(
+    commitment: *const G1Projective,
+    witness: *const G1Projective,
+    element: Scalar,
+    g2_tau: *const G2Projective,
+) -> bool {
+    let commitment_affine = G1Affine::from(*commitment);
+    let witness_affine = G1Affine::from(*witness);
+
+    // [tau]_2 + element*[1]_2
+    let exponent = *g2_tau + G2Projective::generator() * element;
+    let exponent_affine = G2Affine::from(exponent);
+
+    let g2_gen_affine = G2Affine::generator();
+
+    // e(A, g2) == e(W, [tau]_2 + element*[1]_2)
+    pairing(&commitment_affine, &g2_gen_affine) == pairing(&witness_affine, &exponent_affine)
+}
+
+/*
+    Produces a SHPLONK-style batch witness proving that every element of `subset` is accumulated in
+    `scalars`, as a single group element `W = g1^{q(tau)}`. `Z_S(x) = prod (x + subset[i])` is the
+    vanishing polynomial of the subset (same `prod(x + a)` convention as `get_coeff_from_roots`), and
+    `q(x) = f(x) / Z_S(x)` is obtained by folding `kate_division` over each root of `Z_S` in turn.
+*/
+/// # Safety
+///
+/// `scalars_ptr`/`subset_ptr`/`points_ptr` must point to at least
+/// `scalars_len`/`subset_len`/`points_len` valid, readable elements, and `return_point` must
+/// point to valid, writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn get_batch_membership_witness_g1(
+    return_point: *mut G1Projective,
+    scalars_ptr: *const Scalar,
+    scalars_len: usize,
+    subset_ptr: *const Scalar,
+    subset_len: usize,
+    points_ptr: *const G1Projective,
+    points_len: usize,
+) {
+    // Create slices from the raw pointers
+    let scalars: &[Scalar] = std::slice::from_raw_parts(scalars_ptr, scalars_len);
+    let subset: &[Scalar] = std::slice::from_raw_parts(subset_ptr, subset_len);
+    let points: &[G1Projective] = std::slice::from_raw_parts(points_ptr, points_len);
+
+    // Get the coefficients of f(x) = prod (x + scalars[i])
+    let domain = domain_for(scalars.len());
+    let mut quotient = get_coeff_from_roots(scalars, &domain);
+
+    // Divide out (x + subset[i]) one root at a time to get q(x) = f(x) / Z_S(x)
+    for &element in subset {
+        quotient = kate_division(&quotient, -element).0;
+    }
+
+    // Commit to q(x) via MSM against the matching prefix of the G1 setup
+    let witness = G1Projective::multi_exp(&points[..quotient.len()], &quotient);
+
+    // Store the result in the return_point
+    *return_point = witness;
+}
+
+/*
+    Verifies a batch membership witness via the pairing check `e(A, g2) == e(W, g2^{Z_S(tau)})`.
+    `vanishing_commitment_g2` is `g2^{Z_S(tau)}`, which the verifier gets by calling
+    `get_poly_commitment_g2` with `subset` as the scalars (it computes the very same `Z_S`).
+*/
+/// # Safety
+///
+/// `commitment`, `witness`, and `vanishing_commitment_g2` must each point to a valid,
+/// readable value.
+#[no_mangle]
+pub unsafe extern "C" fn verify_batch_membership(
+    commitment: *const G1Projective,
+    witness: *const G1Projective,
+    vanishing_commitment_g2: *const G2Projective,
+) -> bool {
+    let commitment_affine = G1Affine::from(*commitment);
+    let witness_affine = G1Affine::from(*witness);
+    let vanishing_affine = G2Affine::from(*vanishing_commitment_g2);
+
+    let g2_gen_affine = G2Affine::generator();
+
+    // e(A, g2) == e(W, g2^{Z_S(tau)})
+    pairing(&commitment_affine, &g2_gen_affine) == pairing(&witness_affine, &vanishing_affine)
+}
+
+/*
+    Produces a non-membership witness proving `element` is NOT accumulated in `scalars`. Dividing
+    `f` by `(x + element)` gives `f(x) = q(x) * (x + element) + c`, and `c = f(-element)` is nonzero
+    exactly when `-element` is not a root of `f`, i.e. `element` is not one of the accumulated
+    members. Returns both the witness `W = g1^{q(tau)}` and the remainder `c` via out parameters.
+*/
+/// # Safety
+///
+/// `scalars_ptr`/`points_ptr` must point to at least `scalars_len`/`points_len` valid,
+/// readable elements, and `witness_return`/`remainder_return` must point to valid, writable
+/// memory.
+#[no_mangle]
+pub unsafe extern "C" fn get_nonmembership_witness_g1(
+    witness_return: *mut G1Projective,
+    remainder_return: *mut Scalar,
+    scalars_ptr: *const Scalar,
+    scalars_len: usize,
+    element: Scalar,
+    points_ptr: *const G1Projective,
+    points_len: usize,
+) {
+    // Create slices from the raw pointers
+    let scalars: &[Scalar] = std::slice::from_raw_parts(scalars_ptr, scalars_len);
+    let points: &[G1Projective] = std::slice::from_raw_parts(points_ptr, points_len);
+
+    // Get the coefficients of f(x) = prod (x + scalars[i])
+    let domain = domain_for(scalars.len());
+    let roots_poly = get_coeff_from_roots(scalars, &domain);
+
+    // Divide by (x + element) to get q(x) and the remainder c = f(-element)
+    let (quotient_poly, remainder) = kate_division(&roots_poly, -element);
+
+    // Commit to q(x) via MSM against the matching prefix of the G1 setup
+    let witness = G1Projective::multi_exp(&points[..quotient_poly.len()], &quotient_poly);
+
+    // Store the results in the return pointers
+    *witness_return = witness;
+    *remainder_return = remainder;
+}
+
+/*
+    Verifies a non-membership witness via the pairing check
+    `e(A, g2) == e(W, [tau]_2 + element*[1]_2) * e(g1^c, g2)`, which follows from
+    `f(tau) = q(tau) * (tau + element) + c`. The remainder `c` is given explicitly by the prover; a
+    genuine member would force `c = 0`, so a zero remainder is rejected outright.
+*/
+/// # Safety
+///
+/// `commitment`, `witness`, and `g2_tau` must each point to a valid, readable value.
+#[no_mangle]
+pub unsafe extern "C" fn verify_nonmembership(
+    commitment: *const G1Projective,
+    witness: *const G1Projective,
+    element: Scalar,
+    remainder: Scalar,
+    g2_tau: *const G2Projective,
+) -> bool {
+    // A zero remainder means `element` is a root of f, i.e. a member
+    if remainder == Scalar::ZERO {
+        return false;
+    }
+
+    let commitment_affine = G1Affine::from(*commitment);
+    let witness_affine = G1Affine::from(*witness);
+
+    // [tau]_2 + element*[1]_2
+    let exponent = *g2_tau + G2Projective::generator() * element;
+    let exponent_affine = G2Affine::from(exponent);
+
+    let g2_gen_affine = G2Affine::generator();
+    let remainder_commitment = G1Affine::from(G1Projective::generator() * remainder);
+
+    // e(A, g2) == e(W, [tau]_2 + element*[1]_2) * e(g1^c, g2)
+    pairing(&commitment_affine, &g2_gen_affine)
+        == pairing(&witness_affine, &exponent_affine)
+            + pairing(&remainder_commitment, &g2_gen_affine)
+}
+
+/*
+    Verifies a batch of membership witnesses against the same commitment with a constant number of
+    pairings instead of `2 * proofs.len()`. A Fiat-Shamir transcript absorbs the commitment and every
+    `(element, witness)` pair and squeezes a challenge `r`; since
+    `e(W_i, [tau]_2 + a_i*[1]_2) = e(W_i, g2^tau) + a_i * e(W_i, g2)` (pairing bilinearity), folding
+    the per-proof checks with powers of `r` collapses them to
+    `e(A * sum r^i, g2) == e(sum r^i*W_i, g2^tau) + e(sum r^i*a_i*W_i, g2)`.
+    Binding the challenge to the witnesses up front prevents a prover from picking witnesses after
+    learning `r`.
+*/
+pub fn batch_verify_membership(
+    commitment: G1Projective,
+    proofs: &[(Scalar, G1Projective)],
+    g2_tau: G2Projective,
+) -> bool {
+    if proofs.is_empty() {
+        return true;
+    }
+
+    let mut transcript = Transcript::new();
+    transcript.absorb_g1(&G1Affine::from(commitment));
+    for (element, witness) in proofs {
+        transcript.absorb_scalar(element);
+        transcript.absorb_g1(&G1Affine::from(*witness));
+    }
+    let r = transcript.squeeze_challenge();
+
+    // Fold every proof in with ascending powers of r: r, r^2, r^3, ...
+    let mut power = Scalar::ONE;
+    let mut commitment_weight = Scalar::ZERO;
+    let mut witness_agg = G1Projective::identity();
+    let mut scaled_witness_agg = G1Projective::identity();
+    for (element, witness) in proofs {
+        power *= r;
+        commitment_weight += power;
+        witness_agg += *witness * power;
+        scaled_witness_agg += *witness * (power * element);
+    }
+
+    let commitment_affine = G1Affine::from(commitment * commitment_weight);
+    let witness_agg_affine = G1Affine::from(witness_agg);
+    let scaled_witness_agg_affine = G1Affine::from(scaled_witness_agg);
+    let g2_gen_affine = G2Affine::generator();
+    let g2_tau_affine = G2Affine::from(g2_tau);
+
+    // e(A * sum r^i, g2) == e(W_agg, g2^tau) + e(V_agg, g2)
+    pairing(&commitment_affine, &g2_gen_affine)
+        == pairing(&witness_agg_affine, &g2_tau_affine)
+            + pairing(&scaled_witness_agg_affine, &g2_gen_affine)
+}
+
+/*
+    Generalizes membership to arbitrary evaluation claims: produces a witness that `f` (built from
+    `scalars`) takes the value `eval_values[i]` at `eval_points[i]` for every claim. `r` is the
+    low-degree polynomial interpolated through the claims via `lagrange_interpolate`, so
+    `q(x) = (f(x) - r(x)) / Z(x)` is exact, where `Z(x) = prod (x - eval_points[i])`. `q` is obtained
+    by folding `kate_division` over each claimed point, then committed via MSM as `W = g1^{q(tau)}`.
+    A plain membership witness is the special case where every `eval_values[i]` is zero.
+*/
+/// # Safety
+///
+/// `scalars_ptr`/`points_ptr` must point to at least `scalars_len`/`points_len` valid, readable
+/// elements, `eval_points_ptr`/`eval_values_ptr` must each point to at least `eval_len` valid,
+/// readable elements, and `return_point` must point to valid, writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn get_opening_witness_g1(
+    return_point: *mut G1Projective,
+    scalars_ptr: *const Scalar,
+    scalars_len: usize,
+    eval_points_ptr: *const Scalar,
+    eval_values_ptr: *const Scalar,
+    eval_len: usize,
+    points_ptr: *const G1Projective,
+    points_len: usize,
+) {
+    // Create slices from the raw pointers
+    let scalars: &[Scalar] = std::slice::from_raw_parts(scalars_ptr, scalars_len);
+    let eval_points: &[Scalar] = std::slice::from_raw_parts(eval_points_ptr, eval_len);
+    let eval_values: &[Scalar] = std::slice::from_raw_parts(eval_values_ptr, eval_len);
+    let points: &[G1Projective] = std::slice::from_raw_parts(points_ptr, points_len);
+
+    // Get the coefficients of f(x) = prod (x + scalars[i])
+    let domain = domain_for(scalars.len());
+    let f_coeffs = get_coeff_from_roots(scalars, &domain);
+
+    // Interpolate r(x), the low-degree polynomial agreeing with f on every claimed point
+    let claims: Vec<(Scalar, Scalar)> = eval_points
+        .iter()
+        .zip(eval_values.iter())
+        .map(|(&z, &y)| (z, y))
+        .collect();
+    let r_coeffs = lagrange_interpolate(&claims);
+
+    // f(x) - r(x)
+    let mut diff = f_coeffs;
+    for (coeff, r_coeff) in diff.iter_mut().zip(r_coeffs.iter()) {
+        *coeff -= r_coeff;
+    }
+
+    // Divide out (x - eval_points[i]) one point at a time to get q(x) = (f(x) - r(x)) / Z(x)
+    let mut quotient = diff;
+    for &z in eval_points {
+        quotient = kate_division(&quotient, z).0;
+    }
+
+    // Commit to q(x) via MSM against the matching prefix of the G1 setup
+    let witness = G1Projective::multi_exp(&points[..quotient.len()], &quotient);
+
+    // Store the result in the return_point
+    *return_point = witness;
+}
+
+/*
+    Verifies an opening witness via the pairing check
+    `e(A - g1^{r(tau)}, g2) == e(W, g2^{Z(tau)})`, which follows from
+    `f(tau) - r(tau) = q(tau) * Z(tau)`. The verifier has no access to `f`, so it reconstructs
+    `g1^{r(tau)}` and `g2^{Z(tau)}` itself via MSM against the respective setups, using the same
+    `lagrange_interpolate`/`get_coeff_from_roots` primitives the prover used.
+*/
+/// # Safety
+///
+/// `commitment` and `witness` must each point to a valid, readable value.
+/// `eval_points_ptr`/`eval_values_ptr` must each point to at least `eval_len` valid, readable
+/// elements, and `g1_setup_ptr`/`g2_setup_ptr` must each point to at least
+/// `g1_setup_len`/`g2_setup_len` valid, readable elements.
+#[no_mangle]
+pub unsafe extern "C" fn verify_opening(
+    commitment: *const G1Projective,
+    witness: *const G1Projective,
+    eval_points_ptr: *const Scalar,
+    eval_values_ptr: *const Scalar,
+    eval_len: usize,
+    g1_setup_ptr: *const G1Projective,
+    g1_setup_len: usize,
+    g2_setup_ptr: *const G2Projective,
+    g2_setup_len: usize,
+) -> bool {
+    let eval_points: &[Scalar] = std::slice::from_raw_parts(eval_points_ptr, eval_len);
+    let eval_values: &[Scalar] = std::slice::from_raw_parts(eval_values_ptr, eval_len);
+    let g1_setup: &[G1Projective] = std::slice::from_raw_parts(g1_setup_ptr, g1_setup_len);
+    let g2_setup: &[G2Projective] = std::slice::from_raw_parts(g2_setup_ptr, g2_setup_len);
+
+    let claims: Vec<(Scalar, Scalar)> = eval_points
+        .iter()
+        .zip(eval_values.iter())
+        .map(|(&z, &y)| (z, y))
+        .collect();
+    let r_coeffs = lagrange_interpolate(&claims);
+    let r_commitment = G1Projective::multi_exp(&g1_setup[..r_coeffs.len()], &r_coeffs);
+
+    // Z(x) = prod (x - eval_points[i]), via get_coeff_from_roots on the negated points
+    let negated_points: Vec<Scalar> = eval_points.iter().map(|z| -z).collect();
+    let domain = domain_for(negated_points.len());
+    let vanishing = get_coeff_from_roots(&negated_points, &domain);
+    let vanishing_commitment = G2Projective::multi_exp(&g2_setup[..vanishing.len()], &vanishing);
+
+    let lhs_affine = G1Affine::from(*commitment - r_commitment);
+    let witness_affine = G1Affine::from(*witness);
+    let vanishing_affine = G2Affine::from(vanishing_commitment);
+    let g2_gen_affine = G2Affine::generator();
+
+    // e(A - g1^{r(tau)}, g2) == e(W, g2^{Z(tau)})
+    pairing(&lhs_affine, &g2_gen_affine) == pairing(&witness_affine, &vanishing_affine)
+}
+
+/*
+    Flattens the outer product of `x_coeffs` and `y_coeffs` into the coefficient grid of the
+    bivariate polynomial `f(X, Y) = f_x(X) * f_y(Y)`, row-major with `Y` the fast-varying index:
+    `grid[i * y_coeffs.len() + j] = x_coeffs[i] * y_coeffs[j]`. This is the order the tensor SRS
+    `g1^{tau_0^i * tau_1^j}` must be supplied in for the grid's MSM to line up.
+*/
+pub fn outer_product(x_coeffs: &[Scalar], y_coeffs: &[Scalar]) -> Vec<Scalar> {
+    let mut grid = Vec::with_capacity(x_coeffs.len() * y_coeffs.len());
+    for &x in x_coeffs {
+        for &y in y_coeffs {
+            grid.push(x * y);
+        }
+    }
+    grid
+}
+
+/*
+    Commits to the bivariate polynomial `f(X, Y) = f_x(X) * f_y(Y)`, where `f_x(X) = prod (X +
+    x_scalars[i])` and `f_y(Y) = prod (Y + y_scalars[j])` (same `prod(x + root)` convention as
+    `get_coeff_from_roots`), following the bi-KZG construction: a single MSM of the flattened `(n+1)
+    x (m+1)` coefficient grid against the tensor SRS `points[i * (m+1) + j] = g1^{tau_0^i *
+    tau_1^j}`. This is the 2D analogue of `get_poly_commitment_g1`, useful for accumulating a set of
+    pairs `(a_i, b_j)` as a nested/2D set.
+*/
+/// # Safety
+///
+/// `x_scalars_ptr`/`y_scalars_ptr`/`points_ptr` must point to at least
+/// `x_scalars_len`/`y_scalars_len`/`points_len` valid, readable elements, and `return_point`
+/// must point to valid, writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn get_bivariate_commitment_g1(
+    return_point: *mut G1Projective,
+    x_scalars_ptr: *const Scalar,
+    x_scalars_len: usize,
+    y_scalars_ptr: *const Scalar,
+    y_scalars_len: usize,
+    points_ptr: *const G1Projective,
+    points_len: usize,
+) {
+    // Create slices from the raw pointers
+    let x_scalars: &[Scalar] = std::slice::from_raw_parts(x_scalars_ptr, x_scalars_len);
+    let y_scalars: &[Scalar] = std::slice::from_raw_parts(y_scalars_ptr, y_scalars_len);
+    let points: &[G1Projective] = std::slice::from_raw_parts(points_ptr, points_len);
+
+    // Get the coefficients of f_x(X) = prod (X + x_scalars[i]) and f_y(Y) = prod (Y + y_scalars[j])
+    let x_domain = domain_for(x_scalars.len());
+    let y_domain = domain_for(y_scalars.len());
+    let f_x = get_coeff_from_roots(x_scalars, &x_domain);
+    let f_y = get_coeff_from_roots(y_scalars, &y_domain);
+
+    // Flatten the outer product into the coefficient grid and commit via a single MSM
+    let grid = outer_product(&f_x, &f_y);
+    let commitment = G1Projective::multi_exp(&points[..grid.len()], &grid);
+
+    // Store the result in the return_point
+    *return_point = commitment;
+}
 
-        // Get the roots polynomial coefficients using the provided scalars
-        let roots_poly = get_coeff_from_roots(scalars);
+/*
+    Produces a partial-opening witness for the X variable: proves that `f(eval_point, Y) =
+    h(Y)` while keeping `Y` committed. Since `f(X, Y) = f_x(X) * f_y(Y)`, dividing out `(X -
+    eval_point)` factors as `q(X, Y) = q_x(X) * f_y(Y)`, where `q_x(X) = (f_x(X) - f_x(eval_point))
+    / (X - eval_point)` comes from `kate_division`. The witness `W = g1^{q(tau_0, tau_1)}` is
+    committed via a single MSM over the matching `n x (m+1)` prefix of the tensor SRS (every row
+    but the one for the dropped top X-degree). `partial_commitment_return` receives `H = g1^{h(tau_1)} =
+    g1^{f_x(eval_point) * f_y(tau_1)}`, committed against the univariate Y-axis SRS, which the
+    verifier needs since it has no access to `f_y` itself.
+*/
+/// # Safety
+///
+/// `x_scalars_ptr`/`y_scalars_ptr` must point to at least `x_scalars_len`/`y_scalars_len`
+/// valid, readable elements, `tensor_points_ptr`/`y_points_ptr` must point to at least
+/// `tensor_points_len`/`y_points_len` valid, readable elements, and
+/// `witness_return`/`partial_commitment_return` must point to valid, writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn get_bivariate_opening_witness_x_g1(
+    witness_return: *mut G1Projective,
+    partial_commitment_return: *mut G1Projective,
+    x_scalars_ptr: *const Scalar,
+    x_scalars_len: usize,
+    y_scalars_ptr: *const Scalar,
+    y_scalars_len: usize,
+    eval_point: Scalar,
+    tensor_points_ptr: *const G1Projective,
+    tensor_points_len: usize,
+    y_points_ptr: *const G1Projective,
+    y_points_len: usize,
+) {
+    // Create slices from the raw pointers
+    let x_scalars: &[Scalar] = std::slice::from_raw_parts(x_scalars_ptr, x_scalars_len);
+    let y_scalars: &[Scalar] = std::slice::from_raw_parts(y_scalars_ptr, y_scalars_len);
+    let tensor_points: &[G1Projective] =
+        std::slice::from_raw_parts(tensor_points_ptr, tensor_points_len);
+    let y_points: &[G1Projective] = std::slice::from_raw_parts(y_points_ptr, y_points_len);
+
+    let x_domain = domain_for(x_scalars.len());
+    let y_domain = domain_for(y_scalars.len());
+    let f_x = get_coeff_from_roots(x_scalars, &x_domain);
+    let f_y = get_coeff_from_roots(y_scalars, &y_domain);
+
+    // q_x(X) = (f_x(X) - f_x(eval_point)) / (X - eval_point)
+    let (q_x, _remainder) = kate_division(&f_x, eval_point);
+
+    // Witness commits to q_x(X) * f_y(Y); the dropped top X-degree leaves a contiguous prefix
+    // of the tensor SRS to MSM against
+    let grid = outer_product(&q_x, &f_y);
+    let witness = G1Projective::multi_exp(&tensor_points[..grid.len()], &grid);
+
+    // h(Y) = f_x(eval_point) * f_y(Y), committed against the univariate Y-axis SRS
+    let f_x_at_eval_point = eval_polynomial(&f_x, eval_point);
+    let scaled_f_y: Vec<Scalar> = f_y.iter().map(|&coeff| coeff * f_x_at_eval_point).collect();
+    let partial_commitment = G1Projective::multi_exp(&y_points[..scaled_f_y.len()], &scaled_f_y);
+
+    // Store the results in the return pointers
+    *witness_return = witness;
+    *partial_commitment_return = partial_commitment;
+}
+
+/*
+    Verifies an X partial-opening witness via the pairing check
+    `e(A - H, g2) == e(W, [tau_0]_2 - eval_point*[1]_2)`, which follows from
+    `f(tau_0, tau_1) - h(tau_1) = q(tau_0, tau_1) * (tau_0 - eval_point)`.
+*/
+/// # Safety
+///
+/// `commitment`, `witness`, `partial_commitment`, and `g2_tau0` must each point to a valid,
+/// readable value.
+#[no_mangle]
+pub unsafe extern "C" fn verify_bivariate_opening_x(
+    commitment: *const G1Projective,
+    witness: *const G1Projective,
+    partial_commitment: *const G1Projective,
+    eval_point: Scalar,
+    g2_tau0: *const G2Projective,
+) -> bool {
+    let commitment_affine = G1Affine::from(*commitment - *partial_commitment);
+    let witness_affine = G1Affine::from(*witness);
+
+    // [tau_0]_2 - eval_point*[1]_2
+    let exponent = *g2_tau0 - G2Projective::generator() * eval_point;
+    let exponent_affine = G2Affine::from(exponent);
+
+    let g2_gen_affine = G2Affine::generator();
+
+    // e(A - H, g2) == e(W, [tau_0]_2 - eval_point*[1]_2)
+    pairing(&commitment_affine, &g2_gen_affine) == pairing(&witness_affine, &exponent_affine)
+}
+
+/*
+    Produces a partial-opening witness for the Y variable: proves that `f(X, eval_point) = h(X)`
+    while keeping `X` committed. Mirrors `get_bivariate_opening_witness_x_g1` with the roles of `X`
+    and `Y` swapped: `q(X, Y) = f_x(X) * q_y(Y)`, where `q_y(Y) = (f_y(Y) - f_y(eval_point)) / (Y -
+    eval_point)`. Here the dropped top Y-degree does not leave a contiguous tensor-SRS prefix (every
+    row loses its last column), so the matching points are gathered row by row before the MSM.
+*/
+/// # Safety
+///
+/// `x_scalars_ptr`/`y_scalars_ptr` must point to at least `x_scalars_len`/`y_scalars_len`
+/// valid, readable elements, `tensor_points_ptr`/`x_points_ptr` must point to at least
+/// `tensor_points_len`/`x_points_len` valid, readable elements, and
+/// `witness_return`/`partial_commitment_return` must point to valid, writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn get_bivariate_opening_witness_y_g1(
+    witness_return: *mut G1Projective,
+    partial_commitment_return: *mut G1Projective,
+    x_scalars_ptr: *const Scalar,
+    x_scalars_len: usize,
+    y_scalars_ptr: *const Scalar,
+    y_scalars_len: usize,
+    eval_point: Scalar,
+    tensor_points_ptr: *const G1Projective,
+    tensor_points_len: usize,
+    x_points_ptr: *const G1Projective,
+    x_points_len: usize,
+) {
+    // Create slices from the raw pointers
+    let x_scalars: &[Scalar] = std::slice::from_raw_parts(x_scalars_ptr, x_scalars_len);
+    let y_scalars: &[Scalar] = std::slice::from_raw_parts(y_scalars_ptr, y_scalars_len);
+    let tensor_points: &[G1Projective] =
+        std::slice::from_raw_parts(tensor_points_ptr, tensor_points_len);
+    let x_points: &[G1Projective] = std::slice::from_raw_parts(x_points_ptr, x_points_len);
 
-        // Perform MSM (Multi-Scalar Multiplication) with the polynomial coefficients and points
-        let commitment = G2Projective::multi_exp(points, &roots_poly);
+    let x_domain = domain_for(x_scalars.len());
+    let y_domain = domain_for(y_scalars.len());
+    let f_x = get_coeff_from_roots(x_scalars, &x_domain);
+    let f_y = get_coeff_from_roots(y_scalars, &y_domain);
 
-        // Store the result in the return_point
-        *return_point = commitment;
+    // q_y(Y) = (f_y(Y) - f_y(eval_point)) / (Y - eval_point)
+    let (q_y, _remainder) = kate_division(&f_y, eval_point);
+
+    // Gather the tensor-SRS points matching f_x(X) * q_y(Y)'s grid: every row keeps only its
+    // first q_y.len() columns, since the full row has y_scalars.len() + 1 columns
+    let stride = y_scalars.len() + 1;
+    let mut selected_points = Vec::with_capacity(f_x.len() * q_y.len());
+    for row in 0..f_x.len() {
+        let start = row * stride;
+        selected_points.extend_from_slice(&tensor_points[start..start + q_y.len()]);
     }
+
+    let grid = outer_product(&f_x, &q_y);
+    let witness = G1Projective::multi_exp(&selected_points, &grid);
+
+    // h(X) = f_x(X) * f_y(eval_point), committed against the univariate X-axis SRS
+    let f_y_at_eval_point = eval_polynomial(&f_y, eval_point);
+    let scaled_f_x: Vec<Scalar> = f_x.iter().map(|&coeff| coeff * f_y_at_eval_point).collect();
+    let partial_commitment = G1Projective::multi_exp(&x_points[..scaled_f_x.len()], &scaled_f_x);
+
+    // Store the results in the return pointers
+    *witness_return = witness;
+    *partial_commitment_return = partial_commitment;
+}
+
+/*
+    Verifies a Y partial-opening witness via the pairing check
+    `e(A - H, g2) == e(W, [tau_1]_2 - eval_point*[1]_2)`, the same identity as
+    `verify_bivariate_opening_x` with `X` and `Y` swapped.
+*/
+/// # Safety
+///
+/// `commitment`, `witness`, `partial_commitment`, and `g2_tau1` must each point to a valid,
+/// readable value.
+#[no_mangle]
+pub unsafe extern "C" fn verify_bivariate_opening_y(
+    commitment: *const G1Projective,
+    witness: *const G1Projective,
+    partial_commitment: *const G1Projective,
+    eval_point: Scalar,
+    g2_tau1: *const G2Projective,
+) -> bool {
+    let commitment_affine = G1Affine::from(*commitment - *partial_commitment);
+    let witness_affine = G1Affine::from(*witness);
+
+    // [tau_1]_2 - eval_point*[1]_2
+    let exponent = *g2_tau1 - G2Projective::generator() * eval_point;
+    let exponent_affine = G2Affine::from(exponent);
+
+    let g2_gen_affine = G2Affine::generator();
+
+    // e(A - H, g2) == e(W, [tau_1]_2 - eval_point*[1]_2)
+    pairing(&commitment_affine, &g2_gen_affine) == pairing(&witness_affine, &exponent_affine)
 }
 
 #[cfg(test)]
@@ -152,7 +845,7 @@ mod test_get_poly_commitments {
         const N: usize = 5;
         // This represents the roots of the polynomial (x + 1)^5 = x^5 + 5x^4 + 10x^3 + 10x^2 + 5x + 1
         // which is a polynomial of degree 5 (so it has 6 coefficients)
-        let roots = vec![
+        let roots = [
             Scalar::ONE,
             Scalar::ONE,
             Scalar::ONE,
@@ -173,7 +866,7 @@ mod test_get_poly_commitments {
         // Add the powers of tau to the vector if size N+1 (to fit the polynomial coefficients)
         scalar_power_of_tau.extend((0..N).scan(Scalar::ONE, |state, _| {
             // Multiply by tau to get the next power
-            *state = *state * scalar_tau;
+            *state *= scalar_tau;
             // Return the new power of tau
             Some(*state)
         }));
@@ -194,22 +887,26 @@ mod test_get_poly_commitments {
         let mut g2_commitment = G2Projective::identity();
 
         // calculate the commitment using the main function for G1
-        get_poly_commitment_g1(
-            &mut g1_commitment,
-            roots.as_ptr(),
-            roots.len(),
-            g1_setup.as_ptr(),
-            g1_setup.len(),
-        );
+        unsafe {
+            get_poly_commitment_g1(
+                &mut g1_commitment,
+                roots.as_ptr(),
+                roots.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
 
         // calculate the commitment using the main function for G2
-        get_poly_commitment_g2(
-            &mut g2_commitment,
-            roots.as_ptr(),
-            roots.len(),
-            g2_setup.as_ptr(),
-            g2_setup.len(),
-        );
+        unsafe {
+            get_poly_commitment_g2(
+                &mut g2_commitment,
+                roots.as_ptr(),
+                roots.len(),
+                g2_setup.as_ptr(),
+                g2_setup.len(),
+            );
+        }
 
         // Perform pairing check
         let g1_affine = G1Affine::from(g1_commitment);
@@ -226,3 +923,504 @@ mod test_get_poly_commitments {
         println!("Pairing check passed!");
     }
 }
+
+#[cfg(test)]
+mod test_membership {
+    use super::*;
+
+    // Builds a trusted setup for `tau` up to degree `n` over both G1 and G2.
+    fn setup(tau: Scalar, n: usize) -> (Vec<G1Projective>, Vec<G2Projective>) {
+        let mut powers_of_tau = vec![Scalar::ONE];
+        powers_of_tau.extend((0..n).scan(Scalar::ONE, |state, _| {
+            *state *= tau;
+            Some(*state)
+        }));
+
+        let g1_setup = powers_of_tau
+            .iter()
+            .map(|x| G1Projective::generator() * x)
+            .collect();
+        let g2_setup = powers_of_tau
+            .iter()
+            .map(|x| G2Projective::generator() * x)
+            .collect();
+
+        (g1_setup, g2_setup)
+    }
+
+    #[test]
+    fn test_membership_witness_verifies() {
+        // The accumulated set {1, 2, 3, 4, 5}
+        let members = [
+            Scalar::from(1u64),
+            Scalar::from(2u64),
+            Scalar::from(3u64),
+            Scalar::from(4u64),
+            Scalar::from(5u64),
+        ];
+
+        let tau = Scalar::from(42u64);
+        let (g1_setup, g2_setup) = setup(tau, members.len());
+
+        let mut commitment = G1Projective::identity();
+        unsafe {
+            get_poly_commitment_g1(
+                &mut commitment,
+                members.as_ptr(),
+                members.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        let mut witness = G1Projective::identity();
+        unsafe {
+            get_membership_witness_g1(
+                &mut witness,
+                members.as_ptr(),
+                members.len(),
+                members[2],
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        assert!(unsafe { verify_membership(&commitment, &witness, members[2], &g2_setup[1]) });
+    }
+
+    #[test]
+    fn test_batch_membership_witness_verifies() {
+        // The accumulated set {1, 2, 3, 4, 5}
+        let members = [
+            Scalar::from(1u64),
+            Scalar::from(2u64),
+            Scalar::from(3u64),
+            Scalar::from(4u64),
+            Scalar::from(5u64),
+        ];
+        // The subset {2, 4} whose membership is proven in one go
+        let subset = [Scalar::from(2u64), Scalar::from(4u64)];
+
+        let tau = Scalar::from(42u64);
+        let (g1_setup, g2_setup) = setup(tau, members.len());
+
+        let mut commitment = G1Projective::identity();
+        unsafe {
+            get_poly_commitment_g1(
+                &mut commitment,
+                members.as_ptr(),
+                members.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        let mut witness = G1Projective::identity();
+        unsafe {
+            get_batch_membership_witness_g1(
+                &mut witness,
+                members.as_ptr(),
+                members.len(),
+                subset.as_ptr(),
+                subset.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        let mut vanishing_commitment = G2Projective::identity();
+        unsafe {
+            get_poly_commitment_g2(
+                &mut vanishing_commitment,
+                subset.as_ptr(),
+                subset.len(),
+                g2_setup.as_ptr(),
+                subset.len() + 1,
+            );
+        }
+
+        assert!(unsafe { verify_batch_membership(&commitment, &witness, &vanishing_commitment) });
+    }
+
+    #[test]
+    fn test_nonmembership_witness_verifies() {
+        // The accumulated set {1, 2, 3, 4, 5}
+        let members = [
+            Scalar::from(1u64),
+            Scalar::from(2u64),
+            Scalar::from(3u64),
+            Scalar::from(4u64),
+            Scalar::from(5u64),
+        ];
+        // 7 is not a member of the accumulated set
+        let non_member = Scalar::from(7u64);
+
+        let tau = Scalar::from(42u64);
+        let (g1_setup, g2_setup) = setup(tau, members.len());
+
+        let mut commitment = G1Projective::identity();
+        unsafe {
+            get_poly_commitment_g1(
+                &mut commitment,
+                members.as_ptr(),
+                members.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        let mut witness = G1Projective::identity();
+        let mut remainder = Scalar::ZERO;
+        unsafe {
+            get_nonmembership_witness_g1(
+                &mut witness,
+                &mut remainder,
+                members.as_ptr(),
+                members.len(),
+                non_member,
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        assert_ne!(remainder, Scalar::ZERO, "7 must not be a root of f");
+
+        assert!(unsafe {
+            verify_nonmembership(&commitment, &witness, non_member, remainder, &g2_setup[1])
+        });
+    }
+
+    #[test]
+    fn test_batch_verify_membership_aggregates() {
+        // The accumulated set {1, 2, 3, 4, 5}
+        let members = [
+            Scalar::from(1u64),
+            Scalar::from(2u64),
+            Scalar::from(3u64),
+            Scalar::from(4u64),
+            Scalar::from(5u64),
+        ];
+
+        let tau = Scalar::from(42u64);
+        let (g1_setup, g2_setup) = setup(tau, members.len());
+
+        let mut commitment = G1Projective::identity();
+        unsafe {
+            get_poly_commitment_g1(
+                &mut commitment,
+                members.as_ptr(),
+                members.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        // Build an individual membership witness for every accumulated element
+        let proofs: Vec<(Scalar, G1Projective)> = members
+            .iter()
+            .map(|&element| {
+                let mut witness = G1Projective::identity();
+                unsafe {
+                    get_membership_witness_g1(
+                        &mut witness,
+                        members.as_ptr(),
+                        members.len(),
+                        element,
+                        g1_setup.as_ptr(),
+                        g1_setup.len(),
+                    );
+                }
+                (element, witness)
+            })
+            .collect();
+
+        assert!(batch_verify_membership(commitment, &proofs, g2_setup[1]));
+
+        // Swapping in a witness for the wrong element must not verify
+        let mut tampered = proofs.clone();
+        tampered[0].0 = Scalar::from(6u64);
+        assert!(!batch_verify_membership(commitment, &tampered, g2_setup[1]));
+    }
+
+    #[test]
+    fn test_opening_witness_verifies() {
+        // The accumulated set {1, 2, 3, 4, 5}
+        let members = [
+            Scalar::from(1u64),
+            Scalar::from(2u64),
+            Scalar::from(3u64),
+            Scalar::from(4u64),
+            Scalar::from(5u64),
+        ];
+        // Arbitrary points, neither of which is a member of the set
+        let eval_points = [Scalar::from(6u64), Scalar::from(7u64)];
+
+        let tau = Scalar::from(42u64);
+        let (g1_setup, g2_setup) = setup(tau, members.len());
+
+        let domain = domain_for(members.len());
+        let f_coeffs = get_coeff_from_roots(&members, &domain);
+        let eval_values: Vec<Scalar> = eval_points
+            .iter()
+            .map(|&z| eval_polynomial(&f_coeffs, z))
+            .collect();
+
+        let mut commitment = G1Projective::identity();
+        unsafe {
+            get_poly_commitment_g1(
+                &mut commitment,
+                members.as_ptr(),
+                members.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        let mut witness = G1Projective::identity();
+        unsafe {
+            get_opening_witness_g1(
+                &mut witness,
+                members.as_ptr(),
+                members.len(),
+                eval_points.as_ptr(),
+                eval_values.as_ptr(),
+                eval_points.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+            );
+        }
+
+        assert!(unsafe {
+            verify_opening(
+                &commitment,
+                &witness,
+                eval_points.as_ptr(),
+                eval_values.as_ptr(),
+                eval_points.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+                g2_setup.as_ptr(),
+                g2_setup.len(),
+            )
+        });
+
+        // Tampering with a claimed value must not verify
+        let mut tampered_values = eval_values.clone();
+        tampered_values[0] += Scalar::ONE;
+        assert!(!unsafe {
+            verify_opening(
+                &commitment,
+                &witness,
+                eval_points.as_ptr(),
+                tampered_values.as_ptr(),
+                eval_points.len(),
+                g1_setup.as_ptr(),
+                g1_setup.len(),
+                g2_setup.as_ptr(),
+                g2_setup.len(),
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod test_bivariate {
+    use super::*;
+
+    // Builds a univariate trusted setup for `tau` up to degree `n` over G1.
+    fn setup_g1(tau: Scalar, n: usize) -> Vec<G1Projective> {
+        let mut powers_of_tau = vec![Scalar::ONE];
+        powers_of_tau.extend((0..n).scan(Scalar::ONE, |state, _| {
+            *state *= tau;
+            Some(*state)
+        }));
+
+        powers_of_tau
+            .iter()
+            .map(|x| G1Projective::generator() * x)
+            .collect()
+    }
+
+    // Builds the tensor SRS `g1^{tau_0^i * tau_1^j}`, row-major with `tau_1` the fast-varying index.
+    fn setup_tensor_g1(tau0: Scalar, tau1: Scalar, n: usize, m: usize) -> Vec<G1Projective> {
+        let powers0 = setup_g1(tau0, n);
+        let powers1: Vec<Scalar> = {
+            let mut powers = vec![Scalar::ONE];
+            powers.extend((0..m).scan(Scalar::ONE, |state, _| {
+                *state *= tau1;
+                Some(*state)
+            }));
+            powers
+        };
+
+        powers0
+            .iter()
+            .flat_map(|&g1_power0| powers1.iter().map(move |&power1| g1_power0 * power1))
+            .collect()
+    }
+
+    #[test]
+    fn test_bivariate_commitment_matches_product_of_univariate_commitments() {
+        // The accumulated X set {1, 2, 3} and Y set {4, 5}
+        let x_members = [Scalar::from(1u64), Scalar::from(2u64), Scalar::from(3u64)];
+        let y_members = [Scalar::from(4u64), Scalar::from(5u64)];
+
+        let tau0 = Scalar::from(42u64);
+        let tau1 = Scalar::from(43u64);
+        let tensor_g1 = setup_tensor_g1(tau0, tau1, x_members.len(), y_members.len());
+
+        let mut commitment = G1Projective::identity();
+        unsafe {
+            get_bivariate_commitment_g1(
+                &mut commitment,
+                x_members.as_ptr(),
+                x_members.len(),
+                y_members.as_ptr(),
+                y_members.len(),
+                tensor_g1.as_ptr(),
+                tensor_g1.len(),
+            );
+        }
+
+        // f(tau_0, tau_1) = f_x(tau_0) * f_y(tau_1), computed independently via the univariate helper
+        let x_domain = domain_for(x_members.len());
+        let y_domain = domain_for(y_members.len());
+        let f_x_at_tau0 = eval_polynomial(&get_coeff_from_roots(&x_members, &x_domain), tau0);
+        let f_y_at_tau1 = eval_polynomial(&get_coeff_from_roots(&y_members, &y_domain), tau1);
+        let expected = G1Projective::generator() * (f_x_at_tau0 * f_y_at_tau1);
+
+        assert_eq!(commitment, expected);
+    }
+
+    #[test]
+    fn test_bivariate_opening_x_verifies() {
+        // The accumulated X set {1, 2, 3} and Y set {4, 5}
+        let x_members = [Scalar::from(1u64), Scalar::from(2u64), Scalar::from(3u64)];
+        let y_members = [Scalar::from(4u64), Scalar::from(5u64)];
+
+        let tau0 = Scalar::from(42u64);
+        let tau1 = Scalar::from(43u64);
+        let tensor_g1 = setup_tensor_g1(tau0, tau1, x_members.len(), y_members.len());
+        let y_g1 = setup_g1(tau1, y_members.len());
+        let g2_tau0 = G2Projective::generator() * tau0;
+
+        let mut commitment = G1Projective::identity();
+        unsafe {
+            get_bivariate_commitment_g1(
+                &mut commitment,
+                x_members.as_ptr(),
+                x_members.len(),
+                y_members.as_ptr(),
+                y_members.len(),
+                tensor_g1.as_ptr(),
+                tensor_g1.len(),
+            );
+        }
+
+        // Open the X variable at a member, keeping Y committed
+        let mut witness = G1Projective::identity();
+        let mut partial_commitment = G1Projective::identity();
+        unsafe {
+            get_bivariate_opening_witness_x_g1(
+                &mut witness,
+                &mut partial_commitment,
+                x_members.as_ptr(),
+                x_members.len(),
+                y_members.as_ptr(),
+                y_members.len(),
+                x_members[1],
+                tensor_g1.as_ptr(),
+                tensor_g1.len(),
+                y_g1.as_ptr(),
+                y_g1.len(),
+            );
+        }
+
+        assert!(unsafe {
+            verify_bivariate_opening_x(
+                &commitment,
+                &witness,
+                &partial_commitment,
+                x_members[1],
+                &g2_tau0,
+            )
+        });
+
+        // Opening at the wrong point must not verify
+        assert!(!unsafe {
+            verify_bivariate_opening_x(
+                &commitment,
+                &witness,
+                &partial_commitment,
+                x_members[0],
+                &g2_tau0,
+            )
+        });
+    }
+
+    #[test]
+    fn test_bivariate_opening_y_verifies() {
+        // The accumulated X set {1, 2, 3} and Y set {4, 5}
+        let x_members = [Scalar::from(1u64), Scalar::from(2u64), Scalar::from(3u64)];
+        let y_members = [Scalar::from(4u64), Scalar::from(5u64)];
+
+        let tau0 = Scalar::from(42u64);
+        let tau1 = Scalar::from(43u64);
+        let tensor_g1 = setup_tensor_g1(tau0, tau1, x_members.len(), y_members.len());
+        let x_g1 = setup_g1(tau0, x_members.len());
+        let g2_tau1 = G2Projective::generator() * tau1;
+
+        let mut commitment = G1Projective::identity();
+        unsafe {
+            get_bivariate_commitment_g1(
+                &mut commitment,
+                x_members.as_ptr(),
+                x_members.len(),
+                y_members.as_ptr(),
+                y_members.len(),
+                tensor_g1.as_ptr(),
+                tensor_g1.len(),
+            );
+        }
+
+        // Open the Y variable at a member, keeping X committed
+        let mut witness = G1Projective::identity();
+        let mut partial_commitment = G1Projective::identity();
+        unsafe {
+            get_bivariate_opening_witness_y_g1(
+                &mut witness,
+                &mut partial_commitment,
+                x_members.as_ptr(),
+                x_members.len(),
+                y_members.as_ptr(),
+                y_members.len(),
+                y_members[0],
+                tensor_g1.as_ptr(),
+                tensor_g1.len(),
+                x_g1.as_ptr(),
+                x_g1.len(),
+            );
+        }
+
+        assert!(unsafe {
+            verify_bivariate_opening_y(
+                &commitment,
+                &witness,
+                &partial_commitment,
+                y_members[0],
+                &g2_tau1,
+            )
+        });
+
+        // Opening at the wrong point must not verify
+        assert!(!unsafe {
+            verify_bivariate_opening_y(
+                &commitment,
+                &witness,
+                &partial_commitment,
+                y_members[1],
+                &g2_tau1,
+            )
+        });
+    }
+}
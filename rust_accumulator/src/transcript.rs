@@ -0,0 +1,44 @@
+use blake2::{Blake2b512, Digest};
+use blstrs::{G1Affine, Scalar};
+use ff::Field;
+
+/*
+    A minimal Fiat-Shamir transcript used to derive the random linear-combination challenge for
+    `batch_verify_membership`. Commitments, elements and witnesses are absorbed in a fixed order
+    via Blake2b so that an adversary cannot choose witnesses after seeing the resulting challenge.
+*/
+pub struct Transcript {
+    hasher: Blake2b512,
+}
+
+impl Transcript {
+    pub fn new() -> Self {
+        Self {
+            hasher: Blake2b512::new(),
+        }
+    }
+
+    pub fn absorb_g1(&mut self, point: &G1Affine) {
+        self.hasher.update(point.to_compressed());
+    }
+
+    pub fn absorb_scalar(&mut self, scalar: &Scalar) {
+        self.hasher.update(scalar.to_bytes_be());
+    }
+
+    /// Consumes the transcript and squeezes a single field challenge out of it.
+    pub fn squeeze_challenge(self) -> Scalar {
+        let digest = self.hasher.finalize();
+        // Fold the wide digest into the field via Horner's rule so every output maps to a valid
+        // scalar, instead of rejecting digests that fall outside the canonical field representation.
+        digest
+            .iter()
+            .fold(Scalar::ZERO, |acc, &byte| acc * Scalar::from(256u64) + Scalar::from(byte as u64))
+    }
+}
+
+impl Default for Transcript {
+    fn default() -> Self {
+        Self::new()
+    }
+}